@@ -0,0 +1,271 @@
+use sha2::{Digest, Sha256};
+
+use crate::{read_u32, take, DecodeError};
+
+/// The compact target of a difficulty-1 block (`0x1d00ffff`), used as the
+/// numerator when converting a target into a human-readable difficulty.
+const MAX_TARGET_BITS: u32 = 0x1d00ffff;
+
+/// An 80-byte Bitcoin block header.
+pub struct BlockHeader {
+    pub version: u32,
+    pub prev_blockhash: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub time: u32,
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+impl BlockHeader {
+    pub fn decode(header_bytes: &[u8]) -> Result<Self, DecodeError> {
+        if header_bytes.len() > 80 {
+            return Err(DecodeError::TrailingBytes(header_bytes.len() - 80));
+        }
+        let mut bytes_slice = header_bytes;
+        let version = read_u32(&mut bytes_slice)?;
+        let prev_blockhash: [u8; 32] = take(&mut bytes_slice, 32)?.try_into().unwrap();
+        let merkle_root: [u8; 32] = take(&mut bytes_slice, 32)?.try_into().unwrap();
+        let time = read_u32(&mut bytes_slice)?;
+        let bits = read_u32(&mut bytes_slice)?;
+        let nonce = read_u32(&mut bytes_slice)?;
+        Ok(BlockHeader {
+            version,
+            prev_blockhash,
+            merkle_root,
+            time,
+            bits,
+            nonce,
+        })
+    }
+
+    fn to_bytes(&self) -> [u8; 80] {
+        let mut bytes = [0_u8; 80];
+        bytes[0..4].copy_from_slice(&self.version.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.prev_blockhash);
+        bytes[36..68].copy_from_slice(&self.merkle_root);
+        bytes[68..72].copy_from_slice(&self.time.to_le_bytes());
+        bytes[72..76].copy_from_slice(&self.bits.to_le_bytes());
+        bytes[76..80].copy_from_slice(&self.nonce.to_le_bytes());
+        bytes
+    }
+
+    pub fn target(&self) -> Target {
+        Target::from_compact(self.bits)
+    }
+
+    /// Double-SHA256s the header and checks the hash, read as a
+    /// little-endian 256-bit number, is at or below the header's target.
+    pub fn validate_pow(&self) -> bool {
+        let mut hash = double_sha256(&self.to_bytes());
+        hash.reverse();
+        hash <= *self.target().as_bytes()
+    }
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let hash1 = Sha256::digest(data);
+    Sha256::digest(hash1).into()
+}
+
+/// A proof-of-work target, stored as a big-endian 256-bit integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Target([u8; 32]);
+
+impl Target {
+    /// Decodes the compact `bits` encoding: the high byte is the exponent
+    /// and the low 3 bytes are the mantissa, giving
+    /// `target = mantissa * 256^(exponent - 3)`. A mantissa with its high
+    /// bit set is historically a negative number and is treated as zero.
+    pub fn from_compact(bits: u32) -> Self {
+        if bits & 0x0080_0000 != 0 {
+            return Target([0; 32]);
+        }
+        let exponent = (bits >> 24) as i32;
+        let mantissa = bits & 0x007f_ffff;
+        let mantissa_bytes = [(mantissa >> 16) as u8, (mantissa >> 8) as u8, mantissa as u8];
+
+        let mut target = [0_u8; 32];
+        let shift = exponent - 3;
+        for (i, &byte) in mantissa_bytes.iter().enumerate() {
+            let position = shift + (2 - i as i32);
+            if (0..32).contains(&position) {
+                target[31 - position as usize] = byte;
+            }
+        }
+        Target(target)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0.iter().fold(0_f64, |acc, &byte| acc * 256_f64 + byte as f64)
+    }
+
+    /// `difficulty = max_target / target`, where `max_target` is the
+    /// difficulty-1 target `0x1d00ffff`.
+    pub fn difficulty(&self) -> f64 {
+        Target::from_compact(MAX_TARGET_BITS).to_f64() / self.to_f64()
+    }
+
+    /// `work = floor(2^256 / (target + 1))`. A zero target (the "mantissa's
+    /// high bit set" case in `from_compact`) divides by 1, giving a quotient
+    /// of `2^256 - 1` that the `+ 1` rounding below would wrap back to zero;
+    /// saturate at the max representable value instead.
+    pub fn work(&self) -> Work {
+        let denominator = add_one(&self.0);
+        let max = [0xff_u8; 32];
+        let (mut quotient, remainder) = divmod(&max, &denominator);
+        if add_one(&remainder) == denominator && quotient != max {
+            quotient = add_one(&quotient);
+        }
+        Work(quotient)
+    }
+}
+
+/// The amount of proof-of-work represented by a target, as a 256-bit
+/// integer: `floor(2^256 / (target + 1))`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Work([u8; 32]);
+
+impl Work {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+fn add_one(bytes: &[u8; 32]) -> [u8; 32] {
+    let mut result = *bytes;
+    for byte in result.iter_mut().rev() {
+        if *byte == 0xff {
+            *byte = 0;
+        } else {
+            *byte += 1;
+            break;
+        }
+    }
+    result
+}
+
+fn sub_in_place(a: &mut [u8; 32], b: &[u8; 32]) {
+    let mut borrow = 0_i16;
+    for i in (0..32).rev() {
+        let mut diff = a[i] as i16 - b[i] as i16 - borrow;
+        if diff < 0 {
+            diff += 256;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        a[i] = diff as u8;
+    }
+}
+
+/// Schoolbook binary long division over big-endian 256-bit integers,
+/// returning `(quotient, remainder)`.
+fn divmod(numerator: &[u8; 32], denominator: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    let mut quotient = [0_u8; 32];
+    let mut remainder = [0_u8; 32];
+    for byte_index in 0..32 {
+        for bit in (0..8).rev() {
+            let mut carry = 0_u8;
+            for i in (0..32).rev() {
+                let next_carry = remainder[i] >> 7;
+                remainder[i] = (remainder[i] << 1) | carry;
+                carry = next_carry;
+            }
+            remainder[31] |= (numerator[byte_index] >> bit) & 1;
+            if remainder >= *denominator {
+                sub_in_place(&mut remainder, denominator);
+                quotient[byte_index] |= 1 << bit;
+            }
+        }
+    }
+    (quotient, remainder)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::{BlockHeader, Target};
+
+    #[test]
+    fn test_from_compact_decodes_mantissa_and_exponent() {
+        // The well-known difficulty-1 target.
+        let target = Target::from_compact(0x1d00ffff);
+        let mut expected = [0_u8; 32];
+        expected[3] = 0x00;
+        expected[4] = 0xff;
+        expected[5] = 0xff;
+        assert_eq!(*target.as_bytes(), expected);
+    }
+
+    #[test]
+    fn test_from_compact_treats_negative_mantissa_as_zero() {
+        let target = Target::from_compact(0x0080_0000);
+        assert_eq!(*target.as_bytes(), [0_u8; 32]);
+    }
+
+    #[test]
+    fn test_difficulty_of_max_target_is_one() {
+        let target = Target::from_compact(0x1d00ffff);
+        assert_eq!(target.difficulty(), 1.0);
+    }
+
+    #[test]
+    fn test_work_of_zero_target_saturates_instead_of_wrapping() {
+        // A zero target (mantissa's high bit set) represents an
+        // unboundedly easy target, so its work should saturate at the
+        // max representable value rather than wrap around to zero.
+        let target = Target::from_compact(0x0080_0000);
+        assert_eq!(*target.work().as_bytes(), [0xff_u8; 32]);
+    }
+
+    #[test]
+    fn test_work_increases_as_target_decreases() {
+        let easy = Target::from_compact(0x1d00ffff);
+        let hard = Target::from_compact(0x1c00ffff);
+        assert!(hard.work() > easy.work());
+    }
+
+    #[test]
+    fn test_validate_pow_fails_against_zero_target() {
+        let header = BlockHeader {
+            version: 1,
+            prev_blockhash: [0; 32],
+            merkle_root: [0; 32],
+            time: 0,
+            bits: 0x0080_0000,
+            nonce: 0,
+        };
+        assert!(!header.validate_pow());
+    }
+
+    #[test]
+    fn test_validate_pow_matches_hash_against_target() {
+        let header = BlockHeader {
+            version: 1,
+            prev_blockhash: [0xab; 32],
+            merkle_root: [0xcd; 32],
+            time: 1_700_000_000,
+            bits: 0x1d00ffff,
+            nonce: 42,
+        };
+
+        let mut header_bytes = Vec::new();
+        header_bytes.extend_from_slice(&1_u32.to_le_bytes());
+        header_bytes.extend_from_slice(&[0xab; 32]);
+        header_bytes.extend_from_slice(&[0xcd; 32]);
+        header_bytes.extend_from_slice(&1_700_000_000_u32.to_le_bytes());
+        header_bytes.extend_from_slice(&0x1d00ffff_u32.to_le_bytes());
+        header_bytes.extend_from_slice(&42_u32.to_le_bytes());
+
+        use sha2::{Digest, Sha256};
+        let hash1 = Sha256::digest(&header_bytes);
+        let mut hash = Sha256::digest(hash1).to_vec();
+        hash.reverse();
+
+        let expected = hash.as_slice() <= header.target().as_bytes().as_slice();
+        assert_eq!(header.validate_pow(), expected);
+    }
+}
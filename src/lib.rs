@@ -0,0 +1,419 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use sha2::{digest::Digest, Sha256};
+use transaction::{Amount, Input, Output, Transaction, Txid};
+pub mod block;
+mod script;
+pub mod transaction;
+
+/// A decoding failure. Carries no backtrace or allocation beyond what's
+/// needed to report the problem, so it works the same with or without `std`.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// Ran out of input while reading a fixed-size field.
+    UnexpectedEof { expected: usize, got: usize },
+    /// Bytes remained in the buffer after the last field (a transaction's
+    /// `lock_time`, or a block header's `nonce`) was read.
+    TrailingBytes(usize),
+    /// A compact size used more bytes than its value required.
+    NonMinimalCompactSize,
+    /// A script or witness item's declared length exceeds the bytes left
+    /// in the buffer.
+    OversizedScript,
+    HexError(hex::FromHexError),
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::UnexpectedEof { expected, got } => {
+                write!(f, "unexpected end of input: expected {} bytes, got {}", expected, got)
+            }
+            DecodeError::TrailingBytes(n) => write!(f, "{} trailing byte(s) after lock_time", n),
+            DecodeError::NonMinimalCompactSize => write!(f, "compact size was not minimally encoded"),
+            DecodeError::OversizedScript => write!(f, "script length exceeds the remaining bytes"),
+            DecodeError::HexError(e) => write!(f, "invalid hex: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+impl From<hex::FromHexError> for DecodeError {
+    fn from(e: hex::FromHexError) -> Self {
+        DecodeError::HexError(e)
+    }
+}
+
+/// Takes the next `n` bytes off the front of `bytes`, advancing the cursor.
+/// Stands in for `std::io::Read::read_exact` so the parser has no `std`
+/// dependency and never silently returns a short, zero-padded buffer.
+fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Result<&'a [u8], DecodeError> {
+    if bytes.len() < n {
+        return Err(DecodeError::UnexpectedEof { expected: n, got: bytes.len() });
+    }
+    let (head, rest) = bytes.split_at(n);
+    *bytes = rest;
+    Ok(head)
+}
+
+fn read_compact_size(transaction_bytes: &mut &[u8]) -> Result<u64, DecodeError> {
+    let compact_size = take(transaction_bytes, 1)?[0];
+    match compact_size {
+        0..=252 => Ok(compact_size as u64),
+        253 => {
+            let value = u16::from_le_bytes(take(transaction_bytes, 2)?.try_into().unwrap());
+            if value < 253 {
+                return Err(DecodeError::NonMinimalCompactSize);
+            }
+            Ok(value as u64)
+        }
+        254 => {
+            let value = u32::from_le_bytes(take(transaction_bytes, 4)?.try_into().unwrap());
+            if value <= u16::MAX as u32 {
+                return Err(DecodeError::NonMinimalCompactSize);
+            }
+            Ok(value as u64)
+        }
+        255 => {
+            let value = u64::from_le_bytes(take(transaction_bytes, 8)?.try_into().unwrap());
+            if value <= u32::MAX as u64 {
+                return Err(DecodeError::NonMinimalCompactSize);
+            }
+            Ok(value)
+        }
+    }
+}
+
+fn read_amount(transaction_bytes: &mut &[u8]) -> Result<Amount, DecodeError> {
+    Ok(Amount::from_sat(u64::from_le_bytes(take(transaction_bytes, 8)?.try_into().unwrap())))
+}
+
+fn read_u32(transaction_bytes: &mut &[u8]) -> Result<u32, DecodeError> {
+    Ok(u32::from_le_bytes(take(transaction_bytes, 4)?.try_into().unwrap()))
+}
+
+fn read_txid(transaction_bytes: &mut &[u8]) -> Result<Txid, DecodeError> {
+    Ok(Txid::from_bytes(take(transaction_bytes, 32)?.try_into().unwrap()))
+}
+
+fn read_script(transaction_bytes: &mut &[u8]) -> Result<String, DecodeError> {
+    let script_size = read_compact_size(transaction_bytes)?;
+    if script_size as usize > transaction_bytes.len() {
+        return Err(DecodeError::OversizedScript);
+    }
+    let buffer = take(transaction_bytes, script_size as usize)?;
+    Ok(hex::encode(buffer))
+}
+
+pub(crate) fn write_compact_size(value: u64) -> Vec<u8> {
+    match value {
+        0..=252 => vec![value as u8],
+        253..=0xffff => {
+            let mut buffer = vec![253_u8];
+            buffer.extend_from_slice(&(value as u16).to_le_bytes());
+            buffer
+        }
+        0x10000..=0xffffffff => {
+            let mut buffer = vec![254_u8];
+            buffer.extend_from_slice(&(value as u32).to_le_bytes());
+            buffer
+        }
+        _ => {
+            let mut buffer = vec![255_u8];
+            buffer.extend_from_slice(&value.to_le_bytes());
+            buffer
+        }
+    }
+}
+
+pub(crate) fn write_script(script_hex: &str) -> Result<Vec<u8>, DecodeError> {
+    let script_bytes = hex::decode(script_hex)?;
+    let mut buffer = write_compact_size(script_bytes.len() as u64);
+    buffer.extend_from_slice(&script_bytes);
+    Ok(buffer)
+}
+
+fn hash_raw_transaction(raw_transaction: &[u8]) -> Txid {
+    let mut hasher = Sha256::new();
+    hasher.update(raw_transaction);
+    let hash1 = hasher.finalize();
+    let mut hasher = Sha256::new();
+    hasher.update(hash1);
+    let hash2 = hasher.finalize();
+    Txid::from_bytes(hash2.into())
+}
+
+const SEGWIT_MARKER: u8 = 0x00;
+const SEGWIT_FLAG: u8 = 0x01;
+
+#[cfg(feature = "std")]
+pub fn decode(transaction_hex: String) -> Result<String, Box<dyn Error>> {
+    let transaction = decode_transaction(transaction_hex)?;
+    let json_inputs = serde_json::to_string_pretty(&transaction)?;
+    println!("transaction: {}", json_inputs);
+    Ok(json_inputs)
+}
+
+/// Without `std` there is no `serde_json`, so the decoded transaction is
+/// rendered with its `Debug` output instead of pretty JSON.
+#[cfg(not(feature = "std"))]
+pub fn decode(transaction_hex: String) -> Result<String, DecodeError> {
+    let transaction = decode_transaction(transaction_hex)?;
+    Ok(format!("{:?}", transaction))
+}
+
+/// Decodes a raw transaction hex string into a structured `Transaction`,
+/// available with or without `std` so embedded/WASM consumers can work with
+/// `Transaction`, `Output.address`, and `consensus_encode` directly instead
+/// of being limited to the string rendering `decode` produces.
+pub fn decode_transaction(transaction_hex: String) -> Result<Transaction, DecodeError> {
+    let transaction_bytes = hex::decode(transaction_hex)?;
+    let mut bytes_slice = transaction_bytes.as_slice();
+    let version = read_u32(&mut bytes_slice)?;
+
+    let is_segwit = bytes_slice.starts_with(&[SEGWIT_MARKER, SEGWIT_FLAG]);
+    if is_segwit {
+        bytes_slice = &bytes_slice[2..];
+    }
+
+    // everything between the marker/flag and lock_time that isn't witness data
+    // is exactly the legacy transaction body, used for txid computation below
+    let legacy_body_start = bytes_slice;
+
+    let input_count = read_compact_size(&mut bytes_slice)?;
+    let mut inputs = vec![];
+    for _ in 0..input_count {
+        let txid = read_txid(&mut bytes_slice)?;
+        let output_index = read_u32(&mut bytes_slice)?;
+        let script_sig = read_script(&mut bytes_slice)?;
+        let sequence = read_u32(&mut bytes_slice)?;
+        let input = Input {
+            txid,
+            output_index,
+            script_sig,
+            sequence,
+            witnesses: vec![],
+        };
+        inputs.push(input);
+    }
+    let output_count = read_compact_size(&mut bytes_slice)?;
+    let mut outputs = vec![];
+    for _ in 0..output_count {
+        let amount = read_amount(&mut bytes_slice)?;
+        let script_pubkey = read_script(&mut bytes_slice)?;
+        let script_info = script::analyze(&hex::decode(&script_pubkey)?);
+        outputs.push(Output {
+            amount,
+            script_pubkey,
+            asm: script_info.asm,
+            script_type: script_info.script_type,
+            address: script_info.address,
+        });
+    }
+
+    let legacy_body = &legacy_body_start[..legacy_body_start.len() - bytes_slice.len()];
+
+    if is_segwit {
+        for input in inputs.iter_mut() {
+            let witness_count = read_compact_size(&mut bytes_slice)?;
+            let mut witness = vec![];
+            for _ in 0..witness_count {
+                witness.push(read_script(&mut bytes_slice)?);
+            }
+            input.witnesses = witness;
+        }
+    }
+
+    let lock_time = read_u32(&mut bytes_slice)?;
+
+    if !bytes_slice.is_empty() {
+        return Err(DecodeError::TrailingBytes(bytes_slice.len()));
+    }
+
+    let mut legacy_transaction_bytes = Vec::with_capacity(4 + legacy_body.len() + 4);
+    legacy_transaction_bytes.extend_from_slice(&version.to_le_bytes());
+    legacy_transaction_bytes.extend_from_slice(legacy_body);
+    legacy_transaction_bytes.extend_from_slice(&lock_time.to_le_bytes());
+
+    let transaction_id = hash_raw_transaction(&legacy_transaction_bytes);
+    let wtxid = if is_segwit {
+        hash_raw_transaction(&transaction_bytes)
+    } else {
+        transaction_id
+    };
+
+    Ok(Transaction {
+        version,
+        inputs,
+        outputs,
+        lock_time,
+        transaction_id,
+        wtxid,
+        is_segwit,
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use crate::read_u32;
+    use super::Error;
+
+    use super::decode;
+    use super::decode_transaction;
+    use super::read_compact_size;
+    use super::DecodeError;
+
+    const LEGACY_TRANSACTION_HEX: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+    // One input (empty script_sig, one 3-byte witness item) paying to a
+    // single p2wpkh output. Built by hand so txid/wtxid are independently
+    // verifiable: txid hashes the body without the marker/flag or witness
+    // data, wtxid hashes the whole thing including both.
+    const SEGWIT_TRANSACTION_HEX: &str = "0100000000010111111111111111111111111111111111111111111111111111111111111111110000000000ffffffff0100e1f5050000000016001402020202020202020202020202020202020202020103aabbcc00000000";
+
+    #[test]
+    fn test_decode_rejects_truncated_input() {
+        // Cuts inside the first input's txid, well before any script-length
+        // check could fire, so this can only fail as a short read.
+        let truncated = &LEGACY_TRANSACTION_HEX[..40];
+        let err = decode_transaction(truncated.to_string()).unwrap_err();
+        assert!(matches!(err, DecodeError::UnexpectedEof { .. }));
+    }
+
+    #[test]
+    fn test_decode_rejects_trailing_bytes() {
+        let mut with_trailing_byte = hex::decode(LEGACY_TRANSACTION_HEX).unwrap();
+        with_trailing_byte.push(0x00);
+        let err = decode_transaction(hex::encode(with_trailing_byte)).unwrap_err();
+        assert!(matches!(err, DecodeError::TrailingBytes(1)));
+    }
+
+    #[test]
+    fn test_read_compact_size_rejects_non_minimal_encoding() {
+        let mut bytes = [253_u8, 10, 0].as_slice();
+        let err = read_compact_size(&mut bytes).unwrap_err();
+        assert!(matches!(err, DecodeError::NonMinimalCompactSize));
+    }
+
+    #[test]
+    fn test_decode_legacy_wtxid_matches_txid() -> Result<(), Box<dyn Error>> {
+        let json = decode(LEGACY_TRANSACTION_HEX.to_string())?;
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["transaction_id"], value["wtxid"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_consensus_encode_round_trips_decoded_bytes() -> Result<(), Box<dyn Error>> {
+        let transaction = decode_transaction(LEGACY_TRANSACTION_HEX.to_string())?;
+        let encoded = transaction.consensus_encode()?;
+        assert_eq!(hex::encode(encoded), LEGACY_TRANSACTION_HEX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_consensus_encode_round_trips_segwit_transaction_with_empty_witnesses() -> Result<(), Box<dyn Error>> {
+        // A legitimate SegWit transaction can have an all-empty witness
+        // stack; is_segwit must come from the marker/flag, not be inferred
+        // from witness contents, or this would silently drop back to the
+        // legacy encoding and fail to round-trip.
+        let mut transaction = decode_transaction(SEGWIT_TRANSACTION_HEX.to_string())?;
+        transaction.inputs[0].witnesses = vec![];
+        let encoded = transaction.consensus_encode()?;
+        assert_eq!(encoded[4], 0x00);
+        assert_eq!(encoded[5], 0x01);
+        let redecoded = decode_transaction(hex::encode(&encoded))?;
+        assert!(redecoded.is_segwit);
+        assert_eq!(redecoded.consensus_encode()?, encoded);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_segwit_transaction() -> Result<(), Box<dyn Error>> {
+        let transaction = decode_transaction(SEGWIT_TRANSACTION_HEX.to_string())?;
+        assert!(transaction.is_segwit);
+        assert_ne!(transaction.transaction_id, transaction.wtxid);
+        assert_eq!(transaction.inputs.len(), 1);
+        assert_eq!(transaction.inputs[0].witnesses, vec!["aabbcc".to_string()]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_consensus_encode_round_trips_segwit_transaction() -> Result<(), Box<dyn Error>> {
+        let transaction = decode_transaction(SEGWIT_TRANSACTION_HEX.to_string())?;
+        let encoded = transaction.consensus_encode()?;
+        assert_eq!(hex::encode(encoded), SEGWIT_TRANSACTION_HEX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_rejects_oversized_script() {
+        // A script-length compact_size that claims far more bytes than
+        // remain in the buffer, with no actual script bytes following, is
+        // exactly the attacker-controlled-length case this error exists for.
+        let oversized_script =
+            "0100000001".to_string() + &"00".repeat(32) + "00000000" + "fdffff";
+        let err = decode_transaction(oversized_script).unwrap_err();
+        assert!(matches!(err, DecodeError::OversizedScript));
+    }
+
+    #[test]
+    fn test_read_compact_size() -> Result<(), Box<dyn Error>> {
+        let mut bytes = [1_u8].as_slice();
+        let count = read_compact_size(&mut bytes)?;
+        assert_eq!(count, 1_u64);
+
+        let mut bytes = [253_u8, 0, 1].as_slice();
+        let count = read_compact_size(&mut bytes)?;
+        assert_eq!(count, 256_u64);
+
+        let mut bytes = [254_u8, 0, 0, 0, 1].as_slice();
+        let count = read_compact_size(&mut bytes)?;
+        assert_eq!(count, 256_u64.pow(3));
+
+        let mut bytes = [255_u8, 0, 0, 0, 0, 0, 0, 0, 1].as_slice();
+        let count = read_compact_size(&mut bytes)?;
+        assert_eq!(count, 256_u64.pow(7));
+
+        let big_tx = "01000000fd204e";
+        let hex = hex::decode(big_tx)?;
+        let mut sl = hex.as_slice();
+        let version = read_u32(&mut sl)?;
+        let count = read_compact_size(&mut sl)?;
+        assert_eq!(version, 1);
+        assert_eq!(count, 20000);
+        Ok(())
+    }
+}
+
+/// Exercises the `no-std` build path specifically: an embedded/WASM consumer
+/// has no `serde_json`/`Box<dyn Error>`, only `decode_transaction`'s
+/// structured `Transaction`, so this pulls in `std` just for the test
+/// harness and checks that path directly rather than via the `decode`
+/// string-rendering entry point.
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_test {
+    extern crate std;
+    use std::string::ToString;
+
+    use super::decode_transaction;
+
+    #[test]
+    fn test_decode_transaction_without_std_feature() {
+        let legacy_transaction_hex = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+        let transaction = decode_transaction(legacy_transaction_hex.to_string()).unwrap();
+        assert_eq!(transaction.version, 1);
+        assert_eq!(transaction.outputs[0].script_type.as_deref(), None);
+        assert!(!transaction.is_segwit);
+    }
+}
@@ -0,0 +1,319 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+
+use sha2::{Digest, Sha256};
+
+const OP_PUSHDATA1: u8 = 0x4c;
+const OP_PUSHDATA2: u8 = 0x4d;
+const OP_PUSHDATA4: u8 = 0x4e;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The disassembled opcodes/pushdata and, for recognized standard
+/// `script_pubkey` patterns, the script's type and decoded address.
+pub struct ScriptInfo {
+    pub asm: String,
+    pub script_type: Option<String>,
+    pub address: Option<String>,
+}
+
+pub fn analyze(script: &[u8]) -> ScriptInfo {
+    let (script_type, address) = classify(script);
+    ScriptInfo {
+        asm: disassemble(script),
+        script_type,
+        address,
+    }
+}
+
+/// Walks a script's bytes, turning each opcode into its mnemonic and each
+/// pushdata opcode into the hex-encoded bytes it pushes.
+fn disassemble(script: &[u8]) -> String {
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < script.len() {
+        let opcode = script[i];
+        i += 1;
+        let push_len = match opcode {
+            0x01..=0x4b => Some(opcode as usize),
+            OP_PUSHDATA1 => script.get(i).map(|&len| {
+                i += 1;
+                len as usize
+            }),
+            OP_PUSHDATA2 => {
+                if i + 2 > script.len() {
+                    None
+                } else {
+                    let len = u16::from_le_bytes([script[i], script[i + 1]]) as usize;
+                    i += 2;
+                    Some(len)
+                }
+            }
+            OP_PUSHDATA4 => {
+                if i + 4 > script.len() {
+                    None
+                } else {
+                    let len = u32::from_le_bytes([script[i], script[i + 1], script[i + 2], script[i + 3]]) as usize;
+                    i += 4;
+                    Some(len)
+                }
+            }
+            _ => {
+                tokens.push(opcode_name(opcode));
+                continue;
+            }
+        };
+        match push_len {
+            Some(len) if i + len <= script.len() => {
+                tokens.push(hex::encode(&script[i..i + len]));
+                i += len;
+            }
+            _ => {
+                tokens.push("[invalid pushdata]".to_string());
+                break;
+            }
+        }
+    }
+    tokens.join(" ")
+}
+
+fn opcode_name(opcode: u8) -> String {
+    match opcode {
+        0x00 => "OP_0".to_string(),
+        0x4f => "OP_1NEGATE".to_string(),
+        0x51..=0x60 => format!("OP_{}", opcode - 0x50),
+        0x61 => "OP_NOP".to_string(),
+        0x63 => "OP_IF".to_string(),
+        0x64 => "OP_NOTIF".to_string(),
+        0x67 => "OP_ELSE".to_string(),
+        0x68 => "OP_ENDIF".to_string(),
+        0x69 => "OP_VERIFY".to_string(),
+        0x6a => "OP_RETURN".to_string(),
+        0x76 => "OP_DUP".to_string(),
+        0x87 => "OP_EQUAL".to_string(),
+        0x88 => "OP_EQUALVERIFY".to_string(),
+        0xa9 => "OP_HASH160".to_string(),
+        0xaa => "OP_HASH256".to_string(),
+        0xac => "OP_CHECKSIG".to_string(),
+        0xad => "OP_CHECKSIGVERIFY".to_string(),
+        0xae => "OP_CHECKMULTISIG".to_string(),
+        0xaf => "OP_CHECKMULTISIGVERIFY".to_string(),
+        other => format!("OP_UNKNOWN({:#04x})", other),
+    }
+}
+
+/// Recognizes the standard `script_pubkey` shapes and decodes the address
+/// they pay to, if any.
+fn classify(script: &[u8]) -> (Option<String>, Option<String>) {
+    if script.len() == 25
+        && script[0] == 0x76
+        && script[1] == 0xa9
+        && script[2] == 0x14
+        && script[23] == 0x88
+        && script[24] == 0xac
+    {
+        return (
+            Some("p2pkh".to_string()),
+            Some(base58check_address(0x00, &script[3..23])),
+        );
+    }
+    if script.len() == 23 && script[0] == 0xa9 && script[1] == 0x14 && script[22] == 0x87 {
+        return (
+            Some("p2sh".to_string()),
+            Some(base58check_address(0x05, &script[2..22])),
+        );
+    }
+    if script.len() == 22 && script[0] == 0x00 && script[1] == 0x14 {
+        return (
+            Some("p2wpkh".to_string()),
+            Some(segwit_address(0, &script[2..])),
+        );
+    }
+    if script.len() == 34 && script[0] == 0x00 && script[1] == 0x20 {
+        return (
+            Some("p2wsh".to_string()),
+            Some(segwit_address(0, &script[2..])),
+        );
+    }
+    if script.first() == Some(&0x6a) {
+        return (Some("nulldata".to_string()), None);
+    }
+    (None, None)
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let hash1 = Sha256::digest(data);
+    Sha256::digest(hash1).into()
+}
+
+/// Base58Check-encodes `version || hash`, appending the first 4 bytes of
+/// `SHA256(SHA256(version || hash))` as a checksum.
+fn base58check_address(version: u8, hash: &[u8]) -> String {
+    let mut payload = Vec::with_capacity(1 + hash.len() + 4);
+    payload.push(version);
+    payload.extend_from_slice(hash);
+    let checksum = double_sha256(&payload);
+    payload.extend_from_slice(&checksum[..4]);
+    base58_encode(&payload)
+}
+
+fn base58_encode(input: &[u8]) -> String {
+    let mut digits: Vec<u8> = vec![0];
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+    let mut result: Vec<u8> = core::iter::repeat_n(BASE58_ALPHABET[0], leading_zeros).collect();
+    result.extend(digits.iter().rev().map(|&d| BASE58_ALPHABET[d as usize]));
+    String::from_utf8(result).expect("base58 alphabet is ASCII")
+}
+
+/// Bech32-encodes a SegWit witness program for mainnet (`bc` hrp), per
+/// BIP141/BIP173. Only witness version 0 is produced by this decoder.
+fn segwit_address(witness_version: u8, program: &[u8]) -> String {
+    let mut data = vec![witness_version];
+    data.extend(convert_bits(program, 8, 5, true));
+    bech32_encode("bc", &data)
+}
+
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Vec<u8> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut ret = Vec::new();
+    let maxv = (1u32 << to_bits) - 1;
+    for &value in data {
+        acc = (acc << from_bits) | value as u32;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            ret.push(((acc >> bits) & maxv) as u8);
+        }
+    }
+    if pad && bits > 0 {
+        ret.push(((acc << (to_bits - bits)) & maxv) as u8);
+    }
+    ret
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    let generator = [0x3b6a57b2u32, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut checksum: u32 = 1;
+    for &value in values {
+        let top = (checksum >> 25) as u8;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ value as u32;
+        for (i, gen) in generator.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= gen;
+            }
+        }
+    }
+    checksum
+}
+
+fn bech32_hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut expanded: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    expanded.push(0);
+    expanded.extend(hrp.bytes().map(|b| b & 31));
+    expanded
+}
+
+fn bech32_create_checksum(hrp: &str, data: &[u8]) -> Vec<u8> {
+    let mut values = bech32_hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0_u8; 6]);
+    let polymod = bech32_polymod(&values) ^ 1;
+    (0..6).map(|i| ((polymod >> (5 * (5 - i))) & 31) as u8).collect()
+}
+
+fn bech32_encode(hrp: &str, data: &[u8]) -> String {
+    let checksum = bech32_create_checksum(hrp, data);
+    let mut result = String::with_capacity(hrp.len() + 1 + data.len() + checksum.len());
+    result.push_str(hrp);
+    result.push('1');
+    for &d in data.iter().chain(checksum.iter()) {
+        result.push(BECH32_CHARSET[d as usize] as char);
+    }
+    result
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::analyze;
+    use super::disassemble;
+
+    #[test]
+    fn test_disassemble_p2pkh() {
+        let script = hex::decode("76a914000102030405060708090a0b0c0d0e0f1011121388ac").unwrap();
+        assert_eq!(
+            disassemble(&script),
+            "OP_DUP OP_HASH160 000102030405060708090a0b0c0d0e0f10111213 OP_EQUALVERIFY OP_CHECKSIG"
+        );
+    }
+
+    #[test]
+    fn test_classify_p2pkh() {
+        let script = hex::decode("76a914000102030405060708090a0b0c0d0e0f1011121388ac").unwrap();
+        let info = analyze(&script);
+        assert_eq!(info.script_type.as_deref(), Some("p2pkh"));
+        assert_eq!(info.address.as_deref(), Some("112D2adLM3UKy4Z4giRbReR6gjWuvHUqB"));
+    }
+
+    #[test]
+    fn test_classify_p2sh() {
+        let script = hex::decode("a914000102030405060708090a0b0c0d0e0f1011121387").unwrap();
+        let info = analyze(&script);
+        assert_eq!(info.script_type.as_deref(), Some("p2sh"));
+        assert_eq!(info.address.as_deref(), Some("31h38a54tFMrR8kzBnP2241MFD2EUHtGha"));
+    }
+
+    #[test]
+    fn test_classify_p2wpkh() {
+        let script = hex::decode("0014000102030405060708090a0b0c0d0e0f10111213").unwrap();
+        let info = analyze(&script);
+        assert_eq!(info.script_type.as_deref(), Some("p2wpkh"));
+        assert_eq!(
+            info.address.as_deref(),
+            Some("bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysn4v0345")
+        );
+    }
+
+    #[test]
+    fn test_classify_p2wsh() {
+        let script = hex::decode(
+            "0020000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        )
+        .unwrap();
+        let info = analyze(&script);
+        assert_eq!(info.script_type.as_deref(), Some("p2wsh"));
+        assert_eq!(
+            info.address.as_deref(),
+            Some("bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0szrtjt7")
+        );
+    }
+
+    #[test]
+    fn test_classify_nulldata() {
+        let script = hex::decode("6a0548656c6c6f").unwrap();
+        let info = analyze(&script);
+        assert_eq!(info.script_type.as_deref(), Some("nulldata"));
+        assert_eq!(info.address, None);
+    }
+
+    #[test]
+    fn test_classify_unrecognized_script_has_no_type_or_address() {
+        let script = hex::decode("51").unwrap();
+        let info = analyze(&script);
+        assert_eq!(info.script_type, None);
+        assert_eq!(info.address, None);
+    }
+}
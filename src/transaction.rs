@@ -0,0 +1,140 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+use serde::Serialize;
+
+use crate::{write_compact_size, write_script, DecodeError};
+
+#[derive(Debug, Serialize)]
+pub struct Transaction {
+    pub version: u32,
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+    pub lock_time: u32,
+    pub transaction_id: Txid,
+    pub wtxid: Txid,
+    /// Whether the transaction was parsed with a SegWit marker/flag. Recorded
+    /// explicitly rather than inferred from witness contents, since a valid
+    /// SegWit transaction's witness stacks may legitimately all be empty.
+    pub is_segwit: bool,
+}
+
+impl Transaction {
+    /// Re-serializes this transaction back into consensus wire bytes, the
+    /// exact inverse of the `read_*` helpers used by `decode`.
+    pub fn consensus_encode(&self) -> Result<Vec<u8>, DecodeError> {
+        let is_segwit = self.is_segwit;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+
+        if is_segwit {
+            bytes.push(0x00);
+            bytes.push(0x01);
+        }
+
+        bytes.extend_from_slice(&write_compact_size(self.inputs.len() as u64));
+        for input in &self.inputs {
+            bytes.extend_from_slice(input.consensus_encode()?.as_slice());
+        }
+
+        bytes.extend_from_slice(&write_compact_size(self.outputs.len() as u64));
+        for output in &self.outputs {
+            bytes.extend_from_slice(output.consensus_encode()?.as_slice());
+        }
+
+        if is_segwit {
+            for input in &self.inputs {
+                bytes.extend_from_slice(&write_compact_size(input.witnesses.len() as u64));
+                for item in &input.witnesses {
+                    bytes.extend_from_slice(&write_script(item)?);
+                }
+            }
+        }
+
+        bytes.extend_from_slice(&self.lock_time.to_le_bytes());
+        Ok(bytes)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Input {
+    pub txid: Txid,
+    pub output_index: u32,
+    pub script_sig: String,
+    pub sequence: u32,
+    pub witnesses: Vec<String>,
+}
+
+impl Input {
+    /// Encodes this input as it appears in the non-witness part of the
+    /// transaction body; witnesses are serialized separately by the caller.
+    fn consensus_encode(&self) -> Result<Vec<u8>, DecodeError> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.txid.as_bytes());
+        bytes.extend_from_slice(&self.output_index.to_le_bytes());
+        bytes.extend_from_slice(&write_script(&self.script_sig)?);
+        bytes.extend_from_slice(&self.sequence.to_le_bytes());
+        Ok(bytes)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Output {
+    pub amount: Amount,
+    pub script_pubkey: String,
+    pub asm: String,
+    pub script_type: Option<String>,
+    pub address: Option<String>,
+}
+
+impl Output {
+    fn consensus_encode(&self) -> Result<Vec<u8>, DecodeError> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.amount.to_sat().to_le_bytes());
+        bytes.extend_from_slice(&write_script(&self.script_pubkey)?);
+        Ok(bytes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Amount(u64);
+
+impl Amount {
+    pub fn from_sat(satoshi: u64) -> Self {
+        Amount(satoshi)
+    }
+
+    pub fn to_sat(self) -> u64 {
+        self.0
+    }
+
+    pub fn to_btc(self) -> f64 {
+        self.0 as f64 / 100_000_000_f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Txid([u8; 32]);
+
+impl Txid {
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Txid(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Serialize for Txid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // txids are conventionally displayed with reversed byte order
+        let mut reversed = self.0;
+        reversed.reverse();
+        serializer.serialize_str(&hex::encode(reversed))
+    }
+}